@@ -56,11 +56,12 @@
 //! }
 //!
 //! impl Close for DeepThought {
+//!     type Output = u32;
 //!     type Error = String;
-//!     fn close(self) -> Result<(), Self::Error> {
+//!     fn close(self) -> Result<u32, Self::Error> {
 //!         match self.0.join() {
 //!             Err(e) => Err(format!("thread panicked: {:?}", e)),
-//!             Ok(_answer) => /*... teardown ...*/ Ok(()),
+//!             Ok(answer) => /*... teardown ...*/ Ok(answer),
 //!         }
 //!     }
 //! }
@@ -71,8 +72,10 @@
 //! used in precisely the same way as the former, using automatic dereferencing
 //! to access members and methods and joining the thread when the object goes
 //! out of scope. The difference is that the latter allows for a more ergonomic
-//! implementation, does not incur any runtime cost, and allows for manual
-//! closing in case error handling is desired.
+//! implementation, needs no [`Option`] checks to reach its members, allows for
+//! manual closing in case error handling is desired, and, unlike `drop`, lets
+//! the teardown sequence hand back a result: closing a `DeepThought` manually
+//! returns the thread's answer instead of discarding it.
 
 pub trait Close {
     /// Defines the `close` method for manual object destruction.
@@ -83,15 +86,69 @@ pub trait Close {
     /// struct MyIOStruct;
     ///
     /// impl close::Close for MyIOStruct {
+    ///     type Output = ();
     ///     type Error = std::io::Error;
     ///     fn close(self) -> std::io::Result<()> {
     ///         // ... fallible i/o code ...
     ///         Ok(())
     ///     }
     /// }
-
+    type Output;
     type Error: std::fmt::Debug;
-    fn close(self) -> Result<(), Self::Error>;
+    fn close(self) -> Result<Self::Output, Self::Error>;
+}
+
+/// The outcome of a close performed implicitly by [`Closing::drop`],
+/// logically `Result<O, E>`; `Closing::drop` discards the success `Output`
+/// since it has nowhere to return it. A thin internal naming aid so
+/// `Closing::drop` reads as "what became of the implicit close" rather than
+/// matching on a bare `Result`.
+enum Finish<O, E> {
+    Closed(O),
+    Failed(E),
+}
+
+impl<O, E> Finish<O, E> {
+    fn from_close_result(result: Result<O, E>) -> Self {
+        match result {
+            Ok(output) => Finish::Closed(output),
+            Err(error) => Finish::Failed(error),
+        }
+    }
+}
+
+/// Selects how [`Closing::drop`] reacts to a close that fails without having
+/// been performed manually.
+///
+/// The default, [`Panic`](CloseOnDropPolicy::Panic), preserves `Closing`'s
+/// original behaviour. It downgrades to logging via [`eprintln!`] rather than
+/// panicking when the thread is already unwinding ([`std::thread::panicking`]
+/// returns `true`), since panicking again there would abort the process.
+#[derive(Default)]
+pub enum CloseOnDropPolicy<E> {
+    /// Panic with the close error, unless already unwinding.
+    #[default]
+    Panic,
+    /// Abort the process immediately.
+    Abort,
+    /// Silently discard the error.
+    Ignore,
+    /// Pass the error to a caller-supplied handler.
+    Log(Box<dyn FnMut(E) + Send>),
+}
+
+impl<E: std::fmt::Debug> CloseOnDropPolicy<E> {
+    fn handle(&mut self, error: E) {
+        match self {
+            CloseOnDropPolicy::Panic if std::thread::panicking() => {
+                eprintln!("failed to close on drop while already unwinding: {:?}", error);
+            }
+            CloseOnDropPolicy::Panic => panic!("failed to close on drop: {:?}", error),
+            CloseOnDropPolicy::Abort => std::process::abort(),
+            CloseOnDropPolicy::Ignore => (),
+            CloseOnDropPolicy::Log(f) => f(error),
+        }
+    }
 }
 
 /// A zero-cost smart pointer that closes on drop.
@@ -113,6 +170,7 @@ pub trait Close {
 /// }
 ///
 /// impl Close for MyIOStruct {
+///     type Output = ();
 ///     type Error = std::io::Error;
 ///     fn close(self) -> std::io::Result<()> {
 ///         // ... fallible i/o code ...
@@ -127,50 +185,168 @@ pub trait Close {
 ///     let t = MyIOStruct::new();
 ///     Ok(())
 /// } // closing t on drop
-#[derive(Debug)]
-pub struct Closing<T: Close>(std::mem::MaybeUninit<T>);
+pub struct Closing<T: Close> {
+    inner: std::mem::MaybeUninit<T>,
+    policy: CloseOnDropPolicy<T::Error>,
+}
+
+impl<T: Close> std::fmt::Debug for Closing<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Closing").field(&**self).finish()
+    }
+}
+
+/// Runs `f`, aborting the process if it panics instead of letting the unwind
+/// proceed. Used by [`Closing`]'s in-place swap methods while a slot is
+/// transiently uninitialized and has no valid `T` to fall back on.
+fn abort_on_unwind<R>(f: impl FnOnce() -> R) -> R {
+    struct AbortOnUnwind;
+    impl Drop for AbortOnUnwind {
+        fn drop(&mut self) {
+            std::process::abort();
+        }
+    }
+    let bomb = AbortOnUnwind;
+    let result = f();
+    std::mem::forget(bomb);
+    result
+}
 
 impl<T: Close> Closing<T> {
+    /// Wraps `value`, closing it on drop per `policy` if it was not closed
+    /// manually. Use [`From`]/`.into()` for the default [`CloseOnDropPolicy::Panic`]
+    /// behaviour.
+    pub fn with_policy(value: T, policy: CloseOnDropPolicy<T::Error>) -> Self {
+        Closing { inner: std::mem::MaybeUninit::new(value), policy }
+    }
     unsafe fn uninit(&mut self) -> T {
         // Retrieve value from MaybeUninit and replace it by uninit. This
-        // private method is the only routine that uninitializes self. Since it
-        // is used only from drop or prior to mem::forget, we can safely assume
-        // init for the duration of the object's lifetime.
-        std::mem::replace(&mut self.0, std::mem::MaybeUninit::uninit()).assume_init()
+        // private method is the only routine that uninitializes self. It is
+        // used from drop, prior to mem::forget, and by reset_with/
+        // close_in_place/reset_or_recover, which immediately write a fresh
+        // value back — the slot is only ever transiently uninitialized
+        // within these methods, never observable from outside them.
+        std::mem::replace(&mut self.inner, std::mem::MaybeUninit::uninit()).assume_init()
     }
     /// Consumes the `Closing`, returning the wrapped value.
     pub fn into_inner(mut self) -> T {
-        // We cannot simply return self.0.assume_init because self implements
-        // the Drop trait. Instead, we swap out the contents and then forget
-        // about self to avoid a segfault in drop.
+        // We cannot simply return self.inner.assume_init because self
+        // implements the Drop trait. Instead, we swap out the contents and
+        // then forget about self to avoid a segfault in drop.
         let inner = unsafe { self.uninit() }; // safe because we call mem:forget next
         std::mem::forget(self);
         inner
     }
+    /// Replaces the wrapped value with `f(old)`, without moving the
+    /// `Closing` itself — useful for reconnect/reopen loops on a long-lived
+    /// `Closing<T>` field.
+    ///
+    /// `f` is responsible for tearing down the old value (e.g. by calling
+    /// [`Close::close`] on it) if that is desired; `reset_with` itself only
+    /// performs the swap. If `f` panics there is no valid `T` to restore the
+    /// slot with, so the process is aborted; see
+    /// [`reset_or_recover`](Self::reset_or_recover) for a variant that
+    /// installs a caller-provided recovery value instead of aborting.
+    pub fn reset_with(&mut self, f: impl FnOnce(T) -> T) {
+        let old = unsafe { self.uninit() };
+        let new = abort_on_unwind(|| f(old));
+        self.inner = std::mem::MaybeUninit::new(new);
+    }
+    /// Closes the wrapped value and replaces it with `make_new()`, without
+    /// moving the `Closing` itself, returning the close's result.
+    ///
+    /// If `make_new` panics there is no valid `T` to restore the slot with,
+    /// so the process is aborted; see
+    /// [`reset_or_recover`](Self::reset_or_recover) for a variant that
+    /// installs a caller-provided recovery value instead of aborting.
+    pub fn close_in_place(&mut self, make_new: impl FnOnce() -> T) -> Result<T::Output, T::Error> {
+        let old = unsafe { self.uninit() };
+        let (result, new) = abort_on_unwind(|| (old.close(), make_new()));
+        self.inner = std::mem::MaybeUninit::new(new);
+        result
+    }
+    /// Like [`reset_with`](Self::reset_with), but if `f` panics, installs
+    /// `recover`'s value into the slot before resuming the unwind, instead of
+    /// aborting the process.
+    pub fn reset_or_recover(&mut self, f: impl FnOnce(T) -> T, recover: impl FnOnce() -> T) {
+        let old = unsafe { self.uninit() };
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(old))) {
+            Ok(new) => self.inner = std::mem::MaybeUninit::new(new),
+            Err(payload) => {
+                // recover() must not itself panic: the slot is uninitialized
+                // until it returns, so a second panic here has no valid T to
+                // fall back on and must abort rather than unwind.
+                let new = abort_on_unwind(recover);
+                self.inner = std::mem::MaybeUninit::new(new);
+                std::panic::resume_unwind(payload);
+            }
+        }
+    }
+    /// Hands the guarded value to foreign (e.g. C) code as an opaque
+    /// pointer, disarming `close` without running it. The pointer carries
+    /// the value's [`CloseOnDropPolicy`] along with it, so a non-default
+    /// policy set via [`with_policy`](Self::with_policy) survives the round
+    /// trip instead of silently reverting to
+    /// [`CloseOnDropPolicy::Panic`] on [`from_foreign`](Self::from_foreign).
+    /// Pair with [`Closing::from_foreign`] to reclaim the value, and its
+    /// close behaviour, once the foreign code is done with it — useful for
+    /// wrapping C libraries that model an `init`/`use`/`exit` lifecycle,
+    /// where the object lives inside a foreign struct before being freed
+    /// back to Rust and closed.
+    pub fn into_foreign(mut self) -> *mut (T, CloseOnDropPolicy<T::Error>) {
+        let value = unsafe { self.uninit() };
+        let policy = std::mem::replace(&mut self.policy, CloseOnDropPolicy::Ignore);
+        std::mem::forget(self);
+        Box::into_raw(Box::new((value, policy)))
+    }
+    /// Reclaims a value previously handed to foreign code via
+    /// [`Closing::into_foreign`], rearming `close` on drop with the policy
+    /// it was handed off with.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been produced by [`Closing::into_foreign`] for the
+    /// same `T`, must not have been freed by any other means, and must not
+    /// be reclaimed more than once.
+    pub unsafe fn from_foreign(ptr: *mut (T, CloseOnDropPolicy<T::Error>)) -> Closing<T> {
+        let (value, policy) = *Box::from_raw(ptr);
+        Closing::with_policy(value, policy)
+    }
+    /// Disarms `close` entirely, handing back the value without ever running
+    /// it. Unlike [`into_inner`](Self::into_inner), the value is leaked with
+    /// `'static` lifetime rather than handed back by value, for cases where
+    /// there is no intention (or need) to ever reclaim it.
+    pub fn leak(self) -> &'static mut T {
+        Box::leak(Box::new(self.into_inner()))
+    }
 }
 
 impl<T: Close> std::convert::From<T> for Closing<T> {
     fn from(value: T) -> Closing<T> {
-        Closing(std::mem::MaybeUninit::new(value))
+        Closing::with_policy(value, CloseOnDropPolicy::default())
     }
 }
 
 impl<T: Close> std::ops::Deref for Closing<T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
-        unsafe { self.0.assume_init_ref() }
+        unsafe { self.inner.assume_init_ref() }
     }
 }
 
 impl<T: Close> std::ops::DerefMut for Closing<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { self.0.assume_init_mut() }
+        unsafe { self.inner.assume_init_mut() }
     }
 }
 
 impl<T: Close> Close for Closing<T> {
+    type Output = T::Output;
     type Error = T::Error;
-    fn close(self) -> Result<(), Self::Error> {
+    fn close(self) -> Result<Self::Output, Self::Error> {
         self.into_inner().close()
     }
 }
@@ -178,91 +354,341 @@ impl<T: Close> Close for Closing<T> {
 impl<T: Close> Drop for Closing<T> {
     fn drop(&mut self) {
         let inner = unsafe { self.uninit() }; // safe because we are in drop
-        inner.close().expect("failed to close on drop");
+        if let Finish::Failed(error) = Finish::from_close_result(inner.close()) {
+            self.policy.handle(error);
+        }
+    }
+}
+
+/// A [`Close`] adapter that attaches ad-hoc teardown logic to a value without
+/// defining a bespoke type and `impl Close`, à la `defer`. Constructed via
+/// [`Closing::defer`], this turns [`Closing`] into a general scope-guard
+/// mechanism: run `f` on scope exit (or on manual [`close`](Close::close)),
+/// propagating `f`'s error.
+///
+/// # Example
+///
+/// ```
+/// use close::{Close, Closing};
+///
+/// let file = std::fs::File::create("/tmp/close-defer-example")?;
+/// let guarded = Closing::defer(file, |f| f.sync_all());
+/// guarded.close()?; // runs the closure now instead of on drop
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CloseFn<T, E: std::fmt::Debug, F: FnOnce(T) -> Result<(), E>> {
+    value: std::mem::ManuallyDrop<T>,
+    f: std::mem::ManuallyDrop<F>,
+}
+
+impl<T, E: std::fmt::Debug, F: FnOnce(T) -> Result<(), E>> CloseFn<T, E, F> {
+    fn new(value: T, f: F) -> Self {
+        CloseFn { value: std::mem::ManuallyDrop::new(value), f: std::mem::ManuallyDrop::new(f) }
+    }
+    unsafe fn take(&mut self) -> (T, F) {
+        // Like Closing::uninit, the only routine that uninitializes self.
+        (std::mem::ManuallyDrop::take(&mut self.value), std::mem::ManuallyDrop::take(&mut self.f))
+    }
+}
+
+impl<T, E: std::fmt::Debug, F: FnOnce(T) -> Result<(), E>> std::ops::Deref for CloseFn<T, E, F> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, E: std::fmt::Debug, F: FnOnce(T) -> Result<(), E>> std::ops::DerefMut for CloseFn<T, E, F> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T, E: std::fmt::Debug, F: FnOnce(T) -> Result<(), E>> Close for CloseFn<T, E, F> {
+    type Output = ();
+    type Error = E;
+    fn close(mut self) -> Result<(), E> {
+        let (value, f) = unsafe { self.take() }; // safe because we call mem::forget next
+        std::mem::forget(self);
+        f(value)
+    }
+}
+
+impl<T, E: std::fmt::Debug, F: FnOnce(T) -> Result<(), E>> Drop for CloseFn<T, E, F> {
+    fn drop(&mut self) {
+        let (value, f) = unsafe { self.take() }; // safe because we are in drop
+        f(value).expect("CloseFn deferred closure failed");
+    }
+}
+
+impl<T, E: std::fmt::Debug, F: FnOnce(T) -> Result<(), E>> Closing<CloseFn<T, E, F>> {
+    /// Wraps `value` together with teardown logic `f`, to be run when the
+    /// returned [`Closing`] is dropped or closed manually.
+    pub fn defer(value: T, f: F) -> Self {
+        CloseFn::new(value, f).into()
+    }
+}
+
+/// Async analogue of [`Close`] for resources whose teardown must `.await`,
+/// such as flushing a network socket or joining a spawned task.
+///
+/// # Example
+///
+/// ```
+/// struct MyAsyncIOStruct;
+///
+/// impl close::AsyncClose for MyAsyncIOStruct {
+///     type Output = ();
+///     type Error = std::io::Error;
+///     async fn close(self) -> std::io::Result<()> {
+///         // ... fallible async i/o code ...
+///         Ok(())
+///     }
+/// }
+/// ```
+#[allow(async_fn_in_trait)] // small, first-party trait; no need for boxed futures yet
+pub trait AsyncClose {
+    type Output;
+    type Error: std::fmt::Debug;
+    async fn close(self) -> Result<Self::Output, Self::Error>;
+}
+
+/// An async-aware [`Closing`] for resources implementing [`AsyncClose`].
+///
+/// Because async work cannot run inside a synchronous [`Drop::drop`],
+/// dropping an `AsyncClosing` without a prior manual [`close`](Self::close)
+/// either spawns the close future via the spawner supplied to
+/// [`AsyncClosing::with_spawner`], or, if none was supplied, panics —
+/// mirroring [`Closing`]'s behaviour when `close` returns an error.
+pub struct AsyncClosing<T: AsyncClose> {
+    inner: std::mem::MaybeUninit<T>,
+    spawner: Option<Box<dyn FnOnce(T) + Send>>,
+}
+
+impl<T: AsyncClose> AsyncClosing<T> {
+    /// Wraps `value`, panicking on drop if it was not closed manually.
+    pub fn new(value: T) -> Self {
+        AsyncClosing { inner: std::mem::MaybeUninit::new(value), spawner: None }
+    }
+    /// Wraps `value`, closing it via `spawner` on drop if it was not closed
+    /// manually. `spawner` receives ownership of `value` and is responsible
+    /// for awaiting its [`close`](AsyncClose::close) future (and handling any
+    /// resulting error) on whatever executor is appropriate.
+    pub fn with_spawner(value: T, spawner: impl FnOnce(T) + Send + 'static) -> Self {
+        AsyncClosing { inner: std::mem::MaybeUninit::new(value), spawner: Some(Box::new(spawner)) }
+    }
+    unsafe fn uninit(&mut self) -> T {
+        // See Closing::uninit: the only routine that uninitializes self.
+        std::mem::replace(&mut self.inner, std::mem::MaybeUninit::uninit()).assume_init()
+    }
+    /// Consumes the `AsyncClosing`, returning the wrapped value.
+    pub fn into_inner(mut self) -> T {
+        let inner = unsafe { self.uninit() }; // safe because we call mem::forget next
+        std::mem::forget(self);
+        inner
+    }
+}
+
+impl<T: AsyncClose> std::convert::From<T> for AsyncClosing<T> {
+    fn from(value: T) -> AsyncClosing<T> {
+        AsyncClosing::new(value)
+    }
+}
+
+impl<T: AsyncClose> std::ops::Deref for AsyncClosing<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.inner.assume_init_ref() }
+    }
+}
+
+impl<T: AsyncClose> std::ops::DerefMut for AsyncClosing<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { self.inner.assume_init_mut() }
+    }
+}
+
+impl<T: AsyncClose> AsyncClose for AsyncClosing<T> {
+    type Output = T::Output;
+    type Error = T::Error;
+    async fn close(self) -> Result<Self::Output, Self::Error> {
+        self.into_inner().close().await
+    }
+}
+
+impl<T: AsyncClose> Drop for AsyncClosing<T> {
+    fn drop(&mut self) {
+        let inner = unsafe { self.uninit() }; // safe because we are in drop
+        match self.spawner.take() {
+            Some(spawner) => spawner(inner),
+            // Mirror Closing::drop: downgrade to logging rather than
+            // panicking again while already unwinding, to avoid a double
+            // panic aborting the process.
+            None if std::thread::panicking() => {
+                eprintln!("AsyncClosing dropped without a prior manual close or spawner while already unwinding");
+            }
+            None => panic!("AsyncClosing dropped without a prior manual close and no spawner was registered"),
+        }
+    }
+}
+
+// Default implementations for AsyncClose, mirroring those for Close below.
+
+impl<T0: AsyncClose> AsyncClose for (T0,) {
+    type Output = T0::Output;
+    type Error = T0::Error;
+    async fn close(self) -> Result<Self::Output, Self::Error> {
+        self.0.close().await
+    }
+}
+
+impl<T0: AsyncClose, T1: AsyncClose> AsyncClose for (T0, T1) {
+    type Output = (T0::Output, T1::Output);
+    type Error = (Option<T0::Error>, Option<T1::Error>);
+    async fn close(self) -> Result<Self::Output, Self::Error> {
+        match (self.0.close().await, self.1.close().await) {
+            (Ok(o0), Ok(o1)) => Ok((o0, o1)),
+            (r0, r1) => Err((r0.err(), r1.err())),
+        }
+    }
+}
+
+impl<T0: AsyncClose, T1: AsyncClose, T2: AsyncClose> AsyncClose for (T0, T1, T2) {
+    type Output = (T0::Output, T1::Output, T2::Output);
+    type Error = (Option<T0::Error>, Option<T1::Error>, Option<T2::Error>);
+    async fn close(self) -> Result<Self::Output, Self::Error> {
+        match (self.0.close().await, self.1.close().await, self.2.close().await) {
+            (Ok(o0), Ok(o1), Ok(o2)) => Ok((o0, o1, o2)),
+            (r0, r1, r2) => Err((r0.err(), r1.err(), r2.err())),
+        }
+    }
+}
+
+impl<T0: AsyncClose, T1: AsyncClose, T2: AsyncClose, T3: AsyncClose> AsyncClose for (T0, T1, T2, T3) {
+    type Output = (T0::Output, T1::Output, T2::Output, T3::Output);
+    type Error = (Option<T0::Error>, Option<T1::Error>, Option<T2::Error>, Option<T3::Error>);
+    async fn close(self) -> Result<Self::Output, Self::Error> {
+        match (self.0.close().await, self.1.close().await, self.2.close().await, self.3.close().await) {
+            (Ok(o0), Ok(o1), Ok(o2), Ok(o3)) => Ok((o0, o1, o2, o3)),
+            (r0, r1, r2, r3) => Err((r0.err(), r1.err(), r2.err(), r3.err())),
+        }
+    }
+}
+
+impl<T: AsyncClose> AsyncClose for Vec<T> {
+    type Output = Vec<T::Output>;
+    type Error = Vec<Option<T::Error>>;
+    async fn close(self) -> Result<Self::Output, Self::Error> {
+        let mut results = Vec::with_capacity(self.len());
+        for item in self {
+            results.push(item.close().await);
+        }
+        if results.iter().all(Result::is_ok) {
+            Ok(results.into_iter().map(Result::unwrap).collect())
+        }
+        else {
+            Err(results.into_iter().map(Result::err).collect())
+        }
+    }
+}
+
+impl<T: AsyncClose> AsyncClose for Box<T> {
+    type Output = T::Output;
+    type Error = T::Error;
+    async fn close(self) -> Result<Self::Output, Self::Error> {
+        (*self).close().await
+    }
+}
+
+impl<T: AsyncClose> AsyncClose for Option<T> {
+    type Output = Option<T::Output>;
+    type Error = T::Error;
+    async fn close(self) -> Result<Self::Output, Self::Error> {
+        match self {
+            Some(v) => v.close().await.map(Some),
+            None => Ok(None),
+        }
     }
 }
 
 // Default implementations
 
 impl<T0: Close> Close for (T0,) {
+    type Output = T0::Output;
     type Error = T0::Error;
-    fn close(self) -> Result<(), Self::Error> {
+    fn close(self) -> Result<Self::Output, Self::Error> {
         self.0.close()
     }
 }
 
 impl<T0: Close, T1: Close> Close for (T0, T1) {
+    type Output = (T0::Output, T1::Output);
     type Error = (Option<T0::Error>, Option<T1::Error>);
-    fn close(self) -> Result<(), Self::Error> {
-        let result = (self.0.close().err(), self.1.close().err());
-        if result.0.is_none() && result.1.is_none() {
-            Ok(())
-        }
-        else {
-            Err(result)
+    fn close(self) -> Result<Self::Output, Self::Error> {
+        match (self.0.close(), self.1.close()) {
+            (Ok(o0), Ok(o1)) => Ok((o0, o1)),
+            (r0, r1) => Err((r0.err(), r1.err())),
         }
     }
 }
 
 impl<T0: Close, T1: Close, T2: Close> Close for (T0, T1, T2) {
+    type Output = (T0::Output, T1::Output, T2::Output);
     type Error = (Option<T0::Error>, Option<T1::Error>, Option<T2::Error>);
-    fn close(self) -> Result<(), Self::Error> {
-        let result = (self.0.close().err(), self.1.close().err(), self.2.close().err());
-        if result.0.is_none() && result.1.is_none() && result.2.is_none() {
-            Ok(())
-        }
-        else {
-            Err(result)
+    fn close(self) -> Result<Self::Output, Self::Error> {
+        match (self.0.close(), self.1.close(), self.2.close()) {
+            (Ok(o0), Ok(o1), Ok(o2)) => Ok((o0, o1, o2)),
+            (r0, r1, r2) => Err((r0.err(), r1.err(), r2.err())),
         }
     }
 }
 
 impl<T0: Close, T1: Close, T2: Close, T3: Close> Close for (T0, T1, T2, T3) {
+    type Output = (T0::Output, T1::Output, T2::Output, T3::Output);
     type Error = (Option<T0::Error>, Option<T1::Error>, Option<T2::Error>, Option<T3::Error>);
-    fn close(self) -> Result<(), Self::Error> {
-        let result = (self.0.close().err(), self.1.close().err(), self.2.close().err(), self.3.close().err());
-        if result.0.is_none() && result.1.is_none() && result.2.is_none() && result.3.is_none() {
-            Ok(())
-        }
-        else {
-            Err(result)
+    fn close(self) -> Result<Self::Output, Self::Error> {
+        match (self.0.close(), self.1.close(), self.2.close(), self.3.close()) {
+            (Ok(o0), Ok(o1), Ok(o2), Ok(o3)) => Ok((o0, o1, o2, o3)),
+            (r0, r1, r2, r3) => Err((r0.err(), r1.err(), r2.err(), r3.err())),
         }
     }
 }
 
 impl<T: Close> Close for Vec<T> {
+    type Output = Vec<T::Output>;
     type Error = Vec<Option<T::Error>>;
-    fn close(self) -> Result<(), Self::Error> {
-        let result: Self::Error = self.into_iter().map(|item| item.close().err()).collect();
-        if result.iter().all(|item| item.is_none()) {
-            Ok(())
+    fn close(self) -> Result<Self::Output, Self::Error> {
+        let results: Vec<Result<T::Output, T::Error>> = self.into_iter().map(Close::close).collect();
+        if results.iter().all(Result::is_ok) {
+            Ok(results.into_iter().map(Result::unwrap).collect())
         }
         else {
-            Err(result)
+            Err(results.into_iter().map(Result::err).collect())
         }
     }
 }
 
 impl<T: Close> Close for Box<T> {
+    type Output = T::Output;
     type Error = T::Error;
-    fn close(self) -> Result<(), Self::Error> {
+    fn close(self) -> Result<Self::Output, Self::Error> {
         (*self).close()
     }
 }
 
 impl<T: Close> Close for Option<T> {
+    type Output = Option<T::Output>;
     type Error = T::Error;
-    fn close(self) -> Result<(), Self::Error> {
-        if let Some(v) = self {
-            v.close()
-        }
-        else {
-            Ok(())
+    fn close(self) -> Result<Self::Output, Self::Error> {
+        match self {
+            Some(v) => v.close().map(Some),
+            None => Ok(None),
         }
     }
 }
 
 impl Close for std::fs::File {
+    type Output = ();
     type Error = std::io::Error;
     fn close(self) -> std::io::Result<()> {
         // From the docs: Files are automatically closed when they go out of
@@ -272,3 +698,364 @@ impl Close for std::fs::File {
         self.sync_all()
     }
 }
+
+impl<W: Close<Error = std::io::Error> + std::io::Write> Close for std::io::BufWriter<W> {
+    type Output = W::Output;
+    type Error = std::io::Error;
+    fn close(mut self) -> std::io::Result<W::Output> {
+        // BufWriter's own Drop flushes but silently discards the result; flush
+        // here first so the error surfaces. Having already flushed, recover
+        // the inner writer via into_parts rather than into_inner, which
+        // re-flushes and would drop (and thus skip closing) the inner writer
+        // were that redundant flush to fail.
+        std::io::Write::flush(&mut self)?;
+        let (inner, _leftover) = self.into_parts();
+        inner.close()
+    }
+}
+
+impl<W: Close<Error = std::io::Error> + std::io::Write> Close for std::io::LineWriter<W> {
+    type Output = W::Output;
+    type Error = std::io::Error;
+    fn close(mut self) -> std::io::Result<W::Output> {
+        std::io::Write::flush(&mut self)?;
+        // Unlike BufWriter, LineWriter exposes no into_parts, so on the
+        // (having already flushed above) vanishingly unlikely case that
+        // into_inner's redundant flush still fails, the inner writer is
+        // dropped ordinarily rather than closed.
+        match self.into_inner() {
+            Ok(inner) => inner.close(),
+            Err(error) => Err(error.into_error()),
+        }
+    }
+}
+
+/// For stream encoders whose teardown must write a final frame or padding
+/// exactly once at end-of-stream — something `flush`, which callers may
+/// invoke any number of times, cannot safely do. `finish` is that guaranteed,
+/// single-shot end-of-stream hook, returning the now-completed inner writer.
+///
+/// Implementors typically forward their [`Close`] impl to `finish`, so that
+/// wrapping the encoder in [`Closing`] gives callers a guaranteed,
+/// error-propagating end-of-stream hook that ordinary `Drop` does not.
+///
+/// # Example
+///
+/// ```
+/// use close::{Close, FinishClose};
+///
+/// struct MyEncoder<W>(W);
+///
+/// impl<W: std::io::Write> FinishClose for MyEncoder<W> {
+///     type Writer = W;
+///     type Error = std::io::Error;
+///     fn finish(mut self) -> std::io::Result<W> {
+///         self.0.write_all(b"\0")?; // trailing padding byte
+///         Ok(self.0)
+///     }
+/// }
+///
+/// impl<W: std::io::Write> Close for MyEncoder<W> {
+///     type Output = W;
+///     type Error = std::io::Error;
+///     fn close(self) -> std::io::Result<W> {
+///         self.finish()
+///     }
+/// }
+/// ```
+pub trait FinishClose {
+    type Writer;
+    type Error: std::fmt::Debug;
+    fn finish(self) -> Result<Self::Writer, Self::Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Close;
+
+    struct Ok1(u32);
+    impl Close for Ok1 {
+        type Output = u32;
+        type Error = &'static str;
+        fn close(self) -> Result<u32, &'static str> {
+            Ok(self.0)
+        }
+    }
+
+    struct Err1;
+    impl Close for Err1 {
+        type Output = u32;
+        type Error = &'static str;
+        fn close(self) -> Result<u32, &'static str> {
+            Err("boom")
+        }
+    }
+
+    #[test]
+    fn tuple_close_propagates_output_on_success() {
+        let pair = (Ok1(1), Ok1(2));
+        assert_eq!(pair.close(), Ok((1, 2)));
+    }
+
+    #[test]
+    fn tuple_close_folds_errors_per_member() {
+        let pair = (Ok1(1), Err1);
+        assert_eq!(pair.close(), Err((None, Some("boom"))));
+    }
+
+    #[test]
+    fn vec_close_propagates_output_on_success() {
+        let values: Vec<Ok1> = vec![Ok1(1), Ok1(2), Ok1(3)];
+        assert_eq!(values.close(), Ok(vec![1, 2, 3]));
+    }
+
+    enum Either {
+        Ok(Ok1),
+        Err(Err1),
+    }
+    impl Close for Either {
+        type Output = u32;
+        type Error = &'static str;
+        fn close(self) -> Result<u32, &'static str> {
+            match self {
+                Either::Ok(v) => v.close(),
+                Either::Err(v) => v.close(),
+            }
+        }
+    }
+
+    #[test]
+    fn vec_close_folds_errors_per_member() {
+        let values = vec![Either::Ok(Ok1(1)), Either::Err(Err1)];
+        assert_eq!(values.close(), Err(vec![None, Some("boom")]));
+    }
+
+    #[test]
+    fn defer_runs_closure_on_manual_close() {
+        let ran = std::cell::Cell::new(false);
+        let guarded = super::Closing::defer(&ran, |ran| {
+            ran.set(true);
+            Ok::<(), &'static str>(())
+        });
+        guarded.close().unwrap();
+        assert!(ran.get());
+    }
+
+    #[test]
+    fn defer_runs_closure_on_drop() {
+        let ran = std::cell::Cell::new(false);
+        {
+            let _guarded = super::Closing::defer(&ran, |ran| {
+                ran.set(true);
+                Ok::<(), &'static str>(())
+            });
+        }
+        assert!(ran.get());
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to close on drop")]
+    fn defer_panics_on_drop_if_closure_fails() {
+        let _guarded = super::Closing::defer((), |_| Err::<(), &'static str>("boom"));
+    }
+
+    #[test]
+    fn drop_ignore_policy_discards_error() {
+        let guarded = super::Closing::with_policy(Err1, super::CloseOnDropPolicy::Ignore);
+        drop(guarded); // must not panic
+    }
+
+    #[test]
+    fn drop_log_policy_hands_error_to_handler() {
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let seen_in_closure = seen.clone();
+        let guarded = super::Closing::with_policy(
+            Err1,
+            super::CloseOnDropPolicy::Log(Box::new(move |error| {
+                *seen_in_closure.lock().unwrap() = Some(error);
+            })),
+        );
+        drop(guarded);
+        assert_eq!(*seen.lock().unwrap(), Some("boom"));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to close on drop")]
+    fn drop_panic_policy_panics_with_the_error() {
+        let _guarded = super::Closing::with_policy(Err1, super::CloseOnDropPolicy::Panic);
+    }
+
+    #[test]
+    fn reset_with_replaces_value_in_place() {
+        let mut guarded: super::Closing<Ok1> = Ok1(1).into();
+        guarded.reset_with(|old| Ok1(old.0 + 1));
+        assert_eq!(guarded.0, 2);
+        guarded.into_inner().close().unwrap();
+    }
+
+    #[test]
+    fn close_in_place_closes_old_value_and_installs_new() {
+        let mut guarded: super::Closing<Ok1> = Ok1(1).into();
+        let result = guarded.close_in_place(|| Ok1(2));
+        assert_eq!(result, Ok(1));
+        assert_eq!(guarded.0, 2);
+        guarded.into_inner().close().unwrap();
+    }
+
+    #[test]
+    fn reset_or_recover_installs_new_value_on_success() {
+        let mut guarded: super::Closing<Ok1> = Ok1(1).into();
+        guarded.reset_or_recover(|old| Ok1(old.0 + 1), || Ok1(0));
+        assert_eq!(guarded.0, 2);
+        guarded.into_inner().close().unwrap();
+    }
+
+    #[test]
+    fn reset_or_recover_installs_recovery_value_and_resumes_unwind_on_panic() {
+        let mut guarded: super::Closing<Ok1> = Ok1(1).into();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            guarded.reset_or_recover(|_| panic!("f failed"), || Ok1(9));
+        }));
+        assert!(result.is_err());
+        assert_eq!(guarded.0, 9);
+        guarded.into_inner().close().unwrap();
+    }
+
+    struct RecordingWriter {
+        buf: Vec<u8>,
+        closed: std::rc::Rc<std::cell::Cell<bool>>,
+    }
+    impl std::io::Write for RecordingWriter {
+        fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+            std::io::Write::write(&mut self.buf, data)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    impl Close for RecordingWriter {
+        type Output = Vec<u8>;
+        type Error = std::io::Error;
+        fn close(self) -> std::io::Result<Vec<u8>> {
+            self.closed.set(true);
+            Ok(self.buf)
+        }
+    }
+
+    #[test]
+    fn buf_writer_close_flushes_and_closes_inner() {
+        let closed = std::rc::Rc::new(std::cell::Cell::new(false));
+        let mut writer = std::io::BufWriter::new(RecordingWriter { buf: Vec::new(), closed: closed.clone() });
+        std::io::Write::write_all(&mut writer, b"hello").unwrap();
+        let output = writer.close().unwrap();
+        assert_eq!(output, b"hello");
+        assert!(closed.get());
+    }
+
+    #[test]
+    fn line_writer_close_flushes_and_closes_inner() {
+        let closed = std::rc::Rc::new(std::cell::Cell::new(false));
+        let mut writer = std::io::LineWriter::new(RecordingWriter { buf: Vec::new(), closed: closed.clone() });
+        std::io::Write::write_all(&mut writer, b"hello\n").unwrap();
+        let output = writer.close().unwrap();
+        assert_eq!(output, b"hello\n");
+        assert!(closed.get());
+    }
+
+    #[test]
+    fn into_foreign_from_foreign_round_trip_preserves_value_and_close_behaviour() {
+        let guarded: super::Closing<Ok1> = Ok1(7).into();
+        let ptr = guarded.into_foreign();
+        let guarded = unsafe { super::Closing::from_foreign(ptr) };
+        assert_eq!(guarded.0, 7);
+        assert_eq!(guarded.into_inner().close(), Ok(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to close on drop")]
+    fn from_foreign_rearms_close_on_drop() {
+        let guarded: super::Closing<Err1> = Err1.into();
+        let ptr = guarded.into_foreign();
+        let _guarded = unsafe { super::Closing::from_foreign(ptr) }; // drops without manual close
+    }
+
+    #[test]
+    fn into_foreign_from_foreign_round_trip_preserves_non_default_policy() {
+        let guarded = super::Closing::with_policy(Err1, super::CloseOnDropPolicy::Ignore);
+        let ptr = guarded.into_foreign();
+        let guarded = unsafe { super::Closing::from_foreign(ptr) };
+        drop(guarded); // must not panic: the Ignore policy must have survived the round trip
+    }
+
+    #[test]
+    fn leak_disarms_close_and_hands_back_a_static_reference() {
+        let guarded: super::Closing<Ok1> = Ok1(5).into();
+        let leaked: &'static mut Ok1 = guarded.leak();
+        assert_eq!(leaked.0, 5);
+    }
+
+    // None of the futures below ever return Pending, so a single poll always
+    // suffices; this is not a general-purpose executor.
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        fn noop(_: *const ()) {}
+        fn clone(_: *const ()) -> std::task::RawWaker {
+            std::task::RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        static VTABLE: std::task::RawWakerVTable = std::task::RawWakerVTable::new(clone, noop, noop, noop);
+        let waker = unsafe { std::task::Waker::from_raw(std::task::RawWaker::new(std::ptr::null(), &VTABLE)) };
+        let mut cx = std::task::Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+        match future.as_mut().poll(&mut cx) {
+            std::task::Poll::Ready(value) => value,
+            std::task::Poll::Pending => panic!("test future unexpectedly pending"),
+        }
+    }
+
+    struct AsyncOk1(u32);
+    impl super::AsyncClose for AsyncOk1 {
+        type Output = u32;
+        type Error = &'static str;
+        async fn close(self) -> Result<u32, &'static str> {
+            Ok(self.0)
+        }
+    }
+
+    struct AsyncErr1;
+    impl super::AsyncClose for AsyncErr1 {
+        type Output = u32;
+        type Error = &'static str;
+        async fn close(self) -> Result<u32, &'static str> {
+            Err("boom")
+        }
+    }
+
+    #[test]
+    fn async_tuple_close_propagates_output_on_success() {
+        let pair = (AsyncOk1(1), AsyncOk1(2));
+        assert_eq!(block_on(super::AsyncClose::close(pair)), Ok((1, 2)));
+    }
+
+    #[test]
+    fn async_tuple_close_folds_errors_per_member() {
+        let pair = (AsyncOk1(1), AsyncErr1);
+        assert_eq!(block_on(super::AsyncClose::close(pair)), Err((None, Some("boom"))));
+    }
+
+    #[test]
+    fn async_closing_spawner_runs_on_drop() {
+        let ran = std::sync::Arc::new(std::sync::Mutex::new(false));
+        let ran_in_spawner = ran.clone();
+        let guarded = super::AsyncClosing::with_spawner(AsyncOk1(1), move |value| {
+            *ran_in_spawner.lock().unwrap() = true;
+            block_on(super::AsyncClose::close(value)).unwrap();
+        });
+        drop(guarded);
+        assert!(*ran.lock().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "AsyncClosing dropped without a prior manual close")]
+    fn async_closing_without_spawner_panics_on_drop() {
+        let _guarded: super::AsyncClosing<AsyncOk1> = AsyncOk1(1).into();
+    }
+}